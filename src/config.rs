@@ -0,0 +1,129 @@
+use ratatui::style::Color;
+use serde::Deserialize;
+use std::fs;
+
+use crate::Direction;
+
+/// name of the config file loaded from the current working directory
+const CONFIG_FILE_NAME: &str = "snake.json5";
+
+/// user-tunable rules, colors, and keybindings, loaded from `snake.json5`
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct Config {
+    pub tick_rate_ms: u64,
+    pub starting_length: usize,
+    pub apple_color: String,
+    pub snake_color: String,
+    pub head_color: String,
+    pub keybindings: Keybindings,
+}
+
+/// key names overriding the hardcoded WASD/arrow/`<Space>`/`<Esc>` handling
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct Keybindings {
+    pub up: Vec<String>,
+    pub down: Vec<String>,
+    pub left: Vec<String>,
+    pub right: Vec<String>,
+    pub play: Vec<String>,
+    pub quit: Vec<String>,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            tick_rate_ms: 128,
+            starting_length: 5,
+            apple_color: "red".into(),
+            snake_color: "green".into(),
+            head_color: "yellow".into(),
+            keybindings: Keybindings::default(),
+        }
+    }
+}
+
+impl Default for Keybindings {
+    fn default() -> Self {
+        Self {
+            up: vec!["Up".into(), "w".into()],
+            down: vec!["Down".into(), "s".into()],
+            left: vec!["Left".into(), "a".into()],
+            right: vec!["Right".into(), "d".into()],
+            play: vec!["Space".into()],
+            quit: vec!["Esc".into()],
+        }
+    }
+}
+
+impl Config {
+    /// loads `snake.json5` from the current directory, falling back to the
+    /// current defaults if the file is absent or fails to parse
+    pub fn load() -> Self {
+        fs::read_to_string(CONFIG_FILE_NAME)
+            .ok()
+            .and_then(|contents| json5::from_str(&contents).ok())
+            .unwrap_or_default()
+    }
+
+    pub fn apple_color(&self) -> Color {
+        parse_color(&self.apple_color, Color::Red)
+    }
+
+    pub fn snake_color(&self) -> Color {
+        parse_color(&self.snake_color, Color::Green)
+    }
+
+    pub fn head_color(&self) -> Color {
+        parse_color(&self.head_color, Color::Yellow)
+    }
+
+    /// resolves a key name (as produced by `App`'s key event handling) to the
+    /// direction it should steer towards, if any keybinding matches
+    pub fn direction_for_key(&self, key: &str) -> Option<Direction> {
+        if self.keybindings.up.iter().any(|k| k == key) {
+            Some(Direction::Up)
+        } else if self.keybindings.down.iter().any(|k| k == key) {
+            Some(Direction::Down)
+        } else if self.keybindings.left.iter().any(|k| k == key) {
+            Some(Direction::Left)
+        } else if self.keybindings.right.iter().any(|k| k == key) {
+            Some(Direction::Right)
+        } else {
+            None
+        }
+    }
+}
+
+/// parses a color from a CSS-style name or a `#rrggbb` hex string, falling
+/// back to `fallback` when the value isn't recognized
+fn parse_color(value: &str, fallback: Color) -> Color {
+    if let Some(hex) = value.strip_prefix('#') {
+        return parse_hex_color(hex).unwrap_or(fallback);
+    }
+
+    match value.to_lowercase().as_str() {
+        "red" => Color::Red,
+        "green" => Color::Green,
+        "yellow" => Color::Yellow,
+        "blue" => Color::Blue,
+        "magenta" => Color::Magenta,
+        "cyan" => Color::Cyan,
+        "white" => Color::White,
+        "black" => Color::Black,
+        "gray" | "grey" => Color::Gray,
+        "darkgray" | "dark_gray" | "dark grey" => Color::DarkGray,
+        _ => fallback,
+    }
+}
+
+fn parse_hex_color(hex: &str) -> Option<Color> {
+    if hex.len() != 6 {
+        return None;
+    }
+    let r = u8::from_str_radix(&hex[0..2], 16).ok()?;
+    let g = u8::from_str_radix(&hex[2..4], 16).ok()?;
+    let b = u8::from_str_radix(&hex[4..6], 16).ok()?;
+    Some(Color::Rgb(r, g, b))
+}
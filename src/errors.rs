@@ -0,0 +1,25 @@
+use color_eyre::{config::HookBuilder, eyre};
+
+use crate::tui;
+
+/// installs panic and error hooks that restore the terminal before reporting,
+/// so a crash doesn't leave the user's shell in raw/alternate-screen mode
+pub fn install_hooks() -> color_eyre::Result<()> {
+    let (panic_hook, eyre_hook) = HookBuilder::default().into_hooks();
+
+    let panic_hook = panic_hook.into_panic_hook();
+    std::panic::set_hook(Box::new(move |panic_info| {
+        let _ = tui::restore();
+        panic_hook(panic_info);
+    }));
+
+    let eyre_hook = eyre_hook.into_eyre_hook();
+    eyre::set_hook(Box::new(
+        move |error: &(dyn std::error::Error + 'static)| {
+            let _ = tui::restore();
+            eyre_hook(error)
+        },
+    ))?;
+
+    Ok(())
+}
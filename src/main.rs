@@ -9,11 +9,49 @@ use ratatui::{
         *,
     },
 };
+use std::collections::VecDeque;
 use std::time::{Duration, Instant};
 
+mod config;
 mod errors;
+mod scores;
 mod tui;
 
+use config::Config;
+use scores::ScoreTable;
+
+/// longest player name accepted on the high-score entry screen
+const MAX_NAME_LEN: usize = 12;
+
+/// starting hunger budget (in points) awarded when an apple spawns
+const HUNGER_START: u64 = 500;
+/// points deducted from the hunger budget on every hunger tick
+const HUNGER_TICK_AMOUNT: u64 = 10;
+/// real-time interval between hunger ticks, independent of `tick_rate`
+const HUNGER_TICK_INTERVAL: Duration = Duration::from_millis(800);
+
+/// fastest the snake is allowed to move, no matter how many apples it has eaten
+const MIN_TICK_RATE: Duration = Duration::from_millis(40);
+/// milliseconds shaved off the tick rate per apple eaten
+const TICK_RATE_STEP_MS: u64 = 4;
+/// apples eaten per displayed difficulty level
+const APPLES_PER_LEVEL: u64 = 5;
+
+/// chance a bonus food spawns alongside a freshly eaten apple
+const BONUS_FOOD_CHANCE: f64 = 0.15;
+/// chance a shrink food spawns alongside a freshly eaten apple
+const SHRINK_FOOD_CHANCE: f64 = 0.1;
+/// ticks a bonus food stays on the field before despawning
+const BONUS_FOOD_LIFETIME_TICKS: u64 = 40;
+/// ticks a shrink food stays on the field before despawning
+const SHRINK_FOOD_LIFETIME_TICKS: u64 = 40;
+/// points awarded for reaching a bonus food
+const BONUS_FOOD_SCORE: u64 = 250;
+/// body segments removed when the snake reaches a shrink food
+const SHRINK_FOOD_AMOUNT: usize = 2;
+/// attempts made to find an open cell before giving up on spawning a special food
+const SPECIAL_FOOD_SPAWN_ATTEMPTS: u32 = 50;
+
 fn main() -> Result<()> {
     errors::install_hooks()?;
     let mut terminal = tui::init()?;
@@ -32,6 +70,18 @@ pub struct App {
     field: Rect,
     score: u64,
     apple: Apple,
+    hunger_remaining: u64,
+    last_hunger_tick: Instant,
+    base_tick_rate: Duration,
+    min_tick_rate: Duration,
+    apples_eaten: u64,
+    walls: Wall,
+    walls_level: u64,
+    config: Config,
+    high_scores: ScoreTable,
+    name_entry: String,
+    is_new_high_score: bool,
+    special_foods: Vec<Food>,
 }
 
 #[derive(Debug, PartialEq)]
@@ -39,21 +89,51 @@ enum AppStatus {
     Menu,
     Playing,
     GameOver,
+    EnteringName,
+    HighScores,
+}
+
+impl Default for App {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl App {
     pub fn new() -> Self {
+        let config = Config::load();
+        let tick_rate = Duration::from_millis(config.tick_rate_ms);
+        let snake = Snake::new(
+            config.starting_length,
+            config.snake_color(),
+            config.head_color(),
+        );
+        let apple_color = config.apple_color();
+
         Self {
             status: AppStatus::Menu,
             exit: false,
-            snake: Snake::new(),
-            tick_rate: Duration::from_millis(128),
+            snake,
+            tick_rate,
             tick_count: 0,
             field: Rect::default(),
             score: 0,
             apple: Apple {
                 point: Point { x: 0.0, y: 0.0 },
+                color: apple_color,
             },
+            hunger_remaining: HUNGER_START,
+            last_hunger_tick: Instant::now(),
+            base_tick_rate: tick_rate,
+            min_tick_rate: MIN_TICK_RATE,
+            apples_eaten: 0,
+            walls: Wall::default(),
+            walls_level: 0,
+            config,
+            high_scores: ScoreTable::load(),
+            name_entry: String::new(),
+            is_new_high_score: false,
+            special_foods: Vec::new(),
         }
     }
 
@@ -75,47 +155,83 @@ impl App {
                 self.tick()?;
                 last_tick = Instant::now();
             }
+
+            if self.status == AppStatus::Playing
+                && self.last_hunger_tick.elapsed() >= HUNGER_TICK_INTERVAL
+            {
+                self.tick_hunger();
+                self.last_hunger_tick = Instant::now();
+            }
         }
         Ok(())
     }
 
+    /// shrinks `tick_rate` towards `min_tick_rate` as more apples are eaten
+    fn recompute_tick_rate(&mut self) {
+        let reduction = self.apples_eaten * TICK_RATE_STEP_MS;
+        let target_ms = (self.base_tick_rate.as_millis() as u64).saturating_sub(reduction);
+        self.tick_rate = Duration::from_millis(target_ms).max(self.min_tick_rate);
+    }
+
+    /// current difficulty level, derived from how many apples have been eaten
+    fn level(&self) -> u64 {
+        self.apples_eaten / APPLES_PER_LEVEL + 1
+    }
+
+    /// drains the hunger budget; starves the snake if it runs out before the apple is reached
+    fn tick_hunger(&mut self) {
+        self.hunger_remaining = self.hunger_remaining.saturating_sub(HUNGER_TICK_AMOUNT);
+        if self.hunger_remaining == 0 {
+            self.trigger_game_over();
+        }
+    }
+
+    /// ends the run, routing to the name-entry screen if the score qualifies
+    /// for the high-score table
+    fn trigger_game_over(&mut self) {
+        if self.status != AppStatus::Playing {
+            return;
+        }
+        self.is_new_high_score = self.high_scores.qualifies(self.score);
+        if self.is_new_high_score {
+            self.name_entry.clear();
+            self.status = AppStatus::EnteringName;
+        } else {
+            self.status = AppStatus::GameOver;
+        }
+    }
+
     fn tick(&mut self) -> Result<()> {
         self.tick_count += 1;
         if self.status == AppStatus::Playing {
-            self.snake.direction = self.snake.next_direction;
+            self.snake.advance_direction();
 
+            let head = *self.snake.body.front().unwrap();
             let head_next_point = match self.snake.direction {
                 Direction::Up => Point {
-                    x: self.snake.body[0].x,
-                    y: self.snake.body[0].y - 1.0,
+                    x: head.x,
+                    y: head.y - 1.0,
                 },
                 Direction::Down => Point {
-                    x: self.snake.body[0].x,
-                    y: self.snake.body[0].y + 1.0,
+                    x: head.x,
+                    y: head.y + 1.0,
                 },
                 Direction::Left => Point {
-                    x: self.snake.body[0].x - 2.0,
-                    y: self.snake.body[0].y,
+                    x: head.x - 2.0,
+                    y: head.y,
                 },
                 Direction::Right => Point {
-                    x: self.snake.body[0].x + 2.0,
-                    y: self.snake.body[0].y,
+                    x: head.x + 2.0,
+                    y: head.y,
                 },
             };
 
-            if head_next_point.x == self.apple.point.x && head_next_point.y == self.apple.point.y {
-                self.snake
-                    .body
-                    .push(self.snake.body.last().unwrap().clone());
-                self.score += 1;
-                self.generate_apple();
-            }
+            let ate_apple = head_next_point == self.apple.point;
 
-            for i in (1..self.snake.body.len()).rev() {
-                self.snake.body[i] = self.snake.body[i - 1];
-                if head_next_point == self.snake.body[i - 1] {
-                    self.status = AppStatus::GameOver;
-                }
+            // the tail is about to move out of the way, so stepping onto it is safe
+            let tail_vacates = !ate_apple && self.snake.body.back() == Some(&head_next_point);
+            if self.snake.body.contains(&head_next_point) && !tail_vacates {
+                self.trigger_game_over();
             }
 
             if head_next_point.x < 0.0
@@ -123,28 +239,102 @@ impl App {
                 || head_next_point.y < 0.0
                 || head_next_point.y > self.field.height as f64 - 3.0
             {
-                self.status = AppStatus::GameOver;
+                self.trigger_game_over();
+            }
+
+            if self.walls.contains(&head_next_point) {
+                self.trigger_game_over();
+            }
+
+            self.snake.body.push_front(head_next_point);
+            if ate_apple {
+                self.score += self.hunger_remaining;
+                self.apples_eaten += 1;
+                self.recompute_tick_rate();
+                self.regenerate_walls_if_new_level();
+                self.generate_apple();
+                self.spawn_special_foods();
+            } else {
+                self.snake.body.pop_back();
             }
 
-            self.snake.body[0] = head_next_point;
+            self.tick_special_foods(head_next_point);
         }
         Ok(())
     }
 
-    fn generate_apple(&mut self) {
-        let mut possible_point = Point {
-            x: (rand::random::<f64>() * ((self.field.width as f64) - 3.0)).floor(),
-            y: (rand::random::<f64>() * ((self.field.height as f64) - 3.0)).floor(),
-        };
+    /// expires stale special foods and applies the effect of any one the head just reached
+    fn tick_special_foods(&mut self, head: Point) {
+        self.special_foods.retain_mut(|food| {
+            food.remaining_ticks = food.remaining_ticks.saturating_sub(1);
+            food.remaining_ticks > 0
+        });
+
+        if let Some(index) = self.special_foods.iter().position(|food| food.point == head) {
+            let food = self.special_foods.remove(index);
+            match food.kind {
+                FoodKind::Bonus => self.score += BONUS_FOOD_SCORE,
+                FoodKind::Shrink => self.snake.shrink(SHRINK_FOOD_AMOUNT),
+            }
+        }
+    }
 
-        if possible_point.x % 2.0 != 0.0 {
-            possible_point.x += 1.0;
+    /// probabilistically spawns bonus/shrink foods after an apple is eaten
+    fn spawn_special_foods(&mut self) {
+        if rand::random::<f64>() < BONUS_FOOD_CHANCE {
+            self.spawn_food(FoodKind::Bonus, BONUS_FOOD_LIFETIME_TICKS);
         }
+        if rand::random::<f64>() < SHRINK_FOOD_CHANCE {
+            self.spawn_food(FoodKind::Shrink, SHRINK_FOOD_LIFETIME_TICKS);
+        }
+    }
 
-        if self.snake.body.contains(&possible_point) {
-            self.generate_apple();
-        } else {
-            self.apple.point = possible_point;
+    fn spawn_food(&mut self, kind: FoodKind, lifetime_ticks: u64) {
+        if let Some(point) = self.random_open_point() {
+            self.special_foods.push(Food {
+                kind,
+                point,
+                remaining_ticks: lifetime_ticks,
+            });
+        }
+    }
+
+    /// finds a cell not occupied by the snake, a wall, the apple, or another special food
+    fn random_open_point(&self) -> Option<Point> {
+        for _ in 0..SPECIAL_FOOD_SPAWN_ATTEMPTS {
+            let mut point = Point {
+                x: (rand::random::<f64>() * ((self.field.width as f64) - 3.0)).floor(),
+                y: (rand::random::<f64>() * ((self.field.height as f64) - 3.0)).floor(),
+            };
+            if point.x % 2.0 != 0.0 {
+                point.x += 1.0;
+            }
+
+            let occupied = self.snake.body.contains(&point)
+                || self.walls.contains(&point)
+                || self.apple.point == point
+                || self.special_foods.iter().any(|food| food.point == point);
+            if !occupied {
+                return Some(point);
+            }
+        }
+        None
+    }
+
+    fn generate_apple(&mut self) {
+        if let Some(point) = self.random_open_point() {
+            self.apple.point = point;
+            self.hunger_remaining = HUNGER_START;
+            self.last_hunger_tick = Instant::now();
+        }
+    }
+
+    /// regenerates the wall layout whenever the difficulty level has advanced
+    fn regenerate_walls_if_new_level(&mut self) {
+        let level = self.level();
+        if level != self.walls_level {
+            self.walls = LevelGenerator::generate(self.field, level, &self.snake);
+            self.walls_level = level;
         }
     }
 
@@ -161,45 +351,89 @@ impl App {
     }
 
     fn handle_key_event(&mut self, key_event: KeyEvent) -> Result<()> {
-        match key_event.code {
-            KeyCode::Esc => self.exit(),
-            KeyCode::Char(' ') => match self.status {
-                AppStatus::Menu => self.start_game(),
-                AppStatus::GameOver => self.start_game(),
+        if self.status == AppStatus::EnteringName {
+            self.handle_name_entry_key_event(key_event);
+            return Ok(());
+        }
+
+        let key = key_name(key_event.code);
+
+        if key_event.code == KeyCode::Enter {
+            match self.status {
+                AppStatus::GameOver | AppStatus::HighScores => self.status = AppStatus::Menu,
                 _ => {}
-            },
-            KeyCode::Enter => match self.status {
-                AppStatus::GameOver => self.status = AppStatus::Menu,
+            }
+            return Ok(());
+        }
+
+        if self.config.keybindings.quit.iter().any(|k| k == &key) {
+            self.exit();
+            return Ok(());
+        }
+
+        if self.status == AppStatus::Menu && key_event.code == KeyCode::Char('h') {
+            self.status = AppStatus::HighScores;
+            return Ok(());
+        }
+
+        if self.config.keybindings.play.iter().any(|k| k == &key) {
+            match self.status {
+                AppStatus::Menu | AppStatus::GameOver => self.start_game(),
                 _ => {}
-            },
-            KeyCode::Up | KeyCode::Char('w') => match self.snake.direction {
-                Direction::Down => {}
-                _ => self.snake.next_direction = Direction::Up,
-            },
-            KeyCode::Down | KeyCode::Char('s') => match self.snake.direction {
-                Direction::Up => {}
-                _ => self.snake.next_direction = Direction::Down,
-            },
-            KeyCode::Left | KeyCode::Char('a') => match self.snake.direction {
-                Direction::Right => {}
-                _ => self.snake.next_direction = Direction::Left,
-            },
-            KeyCode::Right | KeyCode::Char('d') => match self.snake.direction {
-                Direction::Left => {}
-                _ => self.snake.next_direction = Direction::Right,
-            },
-            _ => {}
+            }
+            return Ok(());
         }
+
+        if let Some(direction) = self.config.direction_for_key(&key) {
+            self.snake.queue_direction(direction);
+        }
+
         Ok(())
     }
 
+    /// handles input while the player is typing their name for the high-score table
+    fn handle_name_entry_key_event(&mut self, key_event: KeyEvent) {
+        match key_event.code {
+            KeyCode::Enter => {
+                let name = if self.name_entry.trim().is_empty() {
+                    "Anonymous".to_string()
+                } else {
+                    self.name_entry.trim().to_string()
+                };
+                self.high_scores.insert(name, self.score);
+                self.high_scores.save();
+                self.status = AppStatus::GameOver;
+            }
+            KeyCode::Backspace => {
+                self.name_entry.pop();
+            }
+            KeyCode::Esc => self.exit(),
+            KeyCode::Char(c) if self.name_entry.len() < MAX_NAME_LEN => {
+                self.name_entry.push(c);
+            }
+            _ => {}
+        }
+    }
+
     fn exit(&mut self) {
         self.exit = true;
     }
 
     fn start_game(&mut self) {
-        self.snake = Snake::new();
+        self.snake = Snake::new(
+            self.config.starting_length,
+            self.config.snake_color(),
+            self.config.head_color(),
+        );
         self.score = 0;
+        self.hunger_remaining = HUNGER_START;
+        self.last_hunger_tick = Instant::now();
+        self.apples_eaten = 0;
+        self.walls_level = 0;
+        self.is_new_high_score = false;
+        self.special_foods.clear();
+        self.recompute_tick_rate();
+        self.regenerate_walls_if_new_level();
         self.generate_apple();
         self.status = AppStatus::Playing;
     }
@@ -215,6 +449,12 @@ impl App {
             AppStatus::GameOver => {
                 frame.render_widget(self.generate_game_over_widget(), frame.size());
             }
+            AppStatus::EnteringName => {
+                frame.render_widget(self.generate_name_entry_widget(), frame.size());
+            }
+            AppStatus::HighScores => {
+                frame.render_widget(self.generate_high_scores_widget(), frame.size());
+            }
         }
     }
 
@@ -223,6 +463,8 @@ impl App {
         let instructions = Title::from(text::Line::from(vec![
             " Play ".into(),
             "<Space>".blue().bold(),
+            " High Scores ".into(),
+            "<h>".blue().bold(),
             " Quit ".into(),
             "<Esc> ".blue().bold(),
         ]));
@@ -240,7 +482,15 @@ impl App {
 
     fn generate_game_widget(&self) -> impl Widget + '_ {
         let game_border = Block::bordered()
-            .title(Title::from(format!(" Score: {} ", self.score)).alignment(Alignment::Center))
+            .title(
+                Title::from(format!(" Score: {}  Level: {} ", self.score, self.level()))
+                    .alignment(Alignment::Center),
+            )
+            .title(
+                Title::from(format!(" Hunger: {} ", self.hunger_remaining))
+                    .alignment(Alignment::Center)
+                    .position(Position::Bottom),
+            )
             .border_set(border::THICK);
 
         Canvas::default()
@@ -249,13 +499,21 @@ impl App {
             .paint(move |ctx| {
                 ctx.draw(&self.snake);
                 ctx.draw(&self.apple);
+                ctx.draw(&self.walls);
+                for food in &self.special_foods {
+                    ctx.draw(food);
+                }
             })
             .x_bounds([0.0, self.field.width as f64 - 3.0])
             .y_bounds([0.0, self.field.height as f64 - 3.0])
     }
 
     fn generate_game_over_widget(&self) -> impl Widget + '_ {
-        let title = Title::from(" Game over ".bold());
+        let title = if self.is_new_high_score {
+            Title::from(" Game over — new high score! ".bold())
+        } else {
+            Title::from(" Game over ".bold())
+        };
         let instructions = Title::from(text::Line::from(vec![
             " Play Again ".into(),
             "<Space>".blue().bold(),
@@ -277,13 +535,90 @@ impl App {
             .borders(Borders::ALL)
             .border_set(border::THICK)
     }
+
+    fn generate_name_entry_widget(&self) -> impl Widget + '_ {
+        let title = Title::from(" New high score! ".bold());
+        let instructions = Title::from(text::Line::from(vec![
+            " Enter name, then ".into(),
+            "<Enter>".blue().bold(),
+            " Quit ".into(),
+            "<Esc> ".blue().bold(),
+        ]));
+        let prompt = Title::from(format!(" Score: {}  Name: {}_ ", self.score, self.name_entry));
+
+        Block::default()
+            .title(title.alignment(Alignment::Center))
+            .title(
+                instructions
+                    .alignment(Alignment::Center)
+                    .position(Position::Bottom),
+            )
+            .title(prompt.alignment(Alignment::Center))
+            .borders(Borders::ALL)
+            .border_set(border::THICK)
+    }
+
+    fn generate_high_scores_widget(&self) -> impl Widget + '_ {
+        let title = Title::from(" High Scores ".bold());
+        let instructions = Title::from(text::Line::from(vec![
+            " Main Menu ".into(),
+            "<Enter>".blue().bold(),
+            " Quit ".into(),
+            "<Esc> ".blue().bold(),
+        ]));
+
+        let rows: Vec<text::Line> = if self.high_scores.entries().is_empty() {
+            vec![text::Line::from(" No scores yet — go set one! ")]
+        } else {
+            self.high_scores
+                .entries()
+                .iter()
+                .enumerate()
+                .map(|(i, entry)| {
+                    text::Line::from(format!(" {:>2}. {:<12} {} ", i + 1, entry.name, entry.score))
+                })
+                .collect()
+        };
+
+        Paragraph::new(rows).alignment(Alignment::Center).block(
+            Block::default()
+                .title(title.alignment(Alignment::Center))
+                .title(
+                    instructions
+                        .alignment(Alignment::Center)
+                        .position(Position::Bottom),
+                )
+                .borders(Borders::ALL)
+                .border_set(border::THICK),
+        )
+    }
 }
 
+/// maps a crossterm `KeyCode` to the key name used to look up `config` keybindings
+fn key_name(code: KeyCode) -> String {
+    match code {
+        KeyCode::Up => "Up".into(),
+        KeyCode::Down => "Down".into(),
+        KeyCode::Left => "Left".into(),
+        KeyCode::Right => "Right".into(),
+        KeyCode::Char(' ') => "Space".into(),
+        KeyCode::Char(c) => c.to_string(),
+        KeyCode::Esc => "Esc".into(),
+        KeyCode::Enter => "Enter".into(),
+        _ => String::new(),
+    }
+}
+
+/// the snake buffers at most this many unprocessed turns, applying one per tick
+const MAX_QUEUED_DIRECTIONS: usize = 2;
+
 #[derive(Debug)]
 pub struct Snake {
-    body: Vec<Point>,
+    body: VecDeque<Point>,
     direction: Direction,
-    next_direction: Direction,
+    queued_directions: VecDeque<Direction>,
+    body_color: Color,
+    head_color: Color,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -294,6 +629,17 @@ pub enum Direction {
     Right,
 }
 
+impl Direction {
+    fn opposite(self) -> Self {
+        match self {
+            Direction::Up => Direction::Down,
+            Direction::Down => Direction::Up,
+            Direction::Left => Direction::Right,
+            Direction::Right => Direction::Left,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub struct Point {
     pub x: f64,
@@ -301,30 +647,122 @@ pub struct Point {
 }
 
 impl Snake {
-    pub fn new() -> Self {
+    pub fn new(length: usize, body_color: Color, head_color: Color) -> Self {
+        let length = length.max(1);
+        let body = (0..length)
+            .map(|i| Point {
+                x: ((length - 1 - i) * 2) as f64,
+                y: 0.0,
+            })
+            .collect();
+
         Self {
-            body: vec![
-                Point { x: 8.0, y: 0.0 },
-                Point { x: 6.0, y: 0.0 },
-                Point { x: 4.0, y: 0.0 },
-                Point { x: 2.0, y: 0.0 },
-                Point { x: 0.0, y: 0.0 },
-            ],
+            body,
             direction: Direction::Right,
-            next_direction: Direction::Right,
+            queued_directions: VecDeque::new(),
+            body_color,
+            head_color,
         }
     }
+
+    /// buffers a turn, rejecting it if it would reverse the snake into itself
+    /// relative to the last direction already queued (or the current one)
+    fn queue_direction(&mut self, direction: Direction) {
+        let last_queued = self
+            .queued_directions
+            .back()
+            .copied()
+            .unwrap_or(self.direction);
+
+        let valid_turn = direction != last_queued && direction != last_queued.opposite();
+        if valid_turn && self.queued_directions.len() < MAX_QUEUED_DIRECTIONS {
+            self.queued_directions.push_back(direction);
+        }
+    }
+
+    /// applies the next queued turn, if any, for this tick
+    fn advance_direction(&mut self) {
+        if let Some(direction) = self.queued_directions.pop_front() {
+            self.direction = direction;
+        }
+    }
+
+    /// removes up to `amount` segments from the tail, always leaving the head in place
+    fn shrink(&mut self, amount: usize) {
+        for _ in 0..amount {
+            if self.body.len() <= 1 {
+                break;
+            }
+            self.body.pop_back();
+        }
+    }
+}
+
+#[cfg(test)]
+mod snake_tests {
+    use super::*;
+
+    fn new_snake() -> Snake {
+        Snake::new(3, Color::White, Color::White)
+    }
+
+    #[test]
+    fn queue_direction_rejects_reversal_of_current_direction() {
+        let mut snake = new_snake();
+        assert_eq!(snake.direction, Direction::Right);
+
+        snake.queue_direction(Direction::Left);
+
+        assert!(snake.queued_directions.is_empty());
+    }
+
+    #[test]
+    fn queue_direction_rejects_reversal_of_already_queued_direction() {
+        let mut snake = new_snake();
+        assert_eq!(snake.direction, Direction::Right);
+
+        snake.queue_direction(Direction::Up);
+        snake.queue_direction(Direction::Down);
+
+        assert_eq!(
+            snake.queued_directions.iter().copied().collect::<Vec<_>>(),
+            vec![Direction::Up]
+        );
+    }
+
+    #[test]
+    fn queue_direction_accepts_turns_up_to_the_cap_and_applies_them_in_order() {
+        let mut snake = new_snake();
+
+        snake.queue_direction(Direction::Up);
+        snake.queue_direction(Direction::Left);
+        snake.queue_direction(Direction::Down);
+
+        assert_eq!(
+            snake.queued_directions.iter().copied().collect::<Vec<_>>(),
+            vec![Direction::Up, Direction::Left]
+        );
+
+        snake.advance_direction();
+        assert_eq!(snake.direction, Direction::Up);
+
+        snake.advance_direction();
+        assert_eq!(snake.direction, Direction::Left);
+
+        snake.advance_direction();
+        assert_eq!(snake.direction, Direction::Left);
+    }
 }
 
 impl Shape for Snake {
     fn draw(&self, painter: &mut Painter) {
-        for point in &self.body[1..] {
-            painter.paint(point.x as usize, point.y as usize, Color::Green);
+        for point in self.body.iter().skip(1) {
+            painter.paint(point.x as usize, point.y as usize, self.body_color);
         }
         painter.paint(
             self.body[0].x as usize,
             self.body[0].y as usize,
-            Color::Yellow,
+            self.head_color,
         );
     }
 }
@@ -332,10 +770,217 @@ impl Shape for Snake {
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub struct Apple {
     pub point: Point,
+    pub color: Color,
 }
 
 impl Shape for Apple {
     fn draw(&self, painter: &mut Painter) {
-        painter.paint(self.point.x as usize, self.point.y as usize, Color::Red);
+        painter.paint(self.point.x as usize, self.point.y as usize, self.color);
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum FoodKind {
+    /// despawns after its lifetime; worth a large flat score bonus
+    Bonus,
+    /// despawns after its lifetime; shrinks the snake when reached
+    Shrink,
+}
+
+impl FoodKind {
+    fn color(self) -> Color {
+        match self {
+            FoodKind::Bonus => Color::Magenta,
+            FoodKind::Shrink => Color::Blue,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Food {
+    kind: FoodKind,
+    point: Point,
+    remaining_ticks: u64,
+}
+
+impl Shape for Food {
+    fn draw(&self, painter: &mut Painter) {
+        painter.paint(
+            self.point.x as usize,
+            self.point.y as usize,
+            self.kind.color(),
+        );
+    }
+}
+
+/// largest number of wall bars a generated level will contain
+const MAX_WALL_BARS: u64 = 6;
+/// minimum number of cells a generated level must leave open (not wall, not
+/// snake), so the snake and a freshly generated apple always have somewhere to go
+const MIN_OPEN_CELLS: usize = 20;
+/// cells directly ahead of the head, in its current direction, that stay clear of
+/// new walls so a mid-run regeneration never traps the snake with no possible input
+const HEAD_SAFETY_RUNWAY: u64 = 3;
+
+#[derive(Debug, Default, Clone)]
+pub struct Wall {
+    cells: Vec<Point>,
+}
+
+impl Wall {
+    fn contains(&self, point: &Point) -> bool {
+        self.cells.contains(point)
+    }
+}
+
+impl Shape for Wall {
+    fn draw(&self, painter: &mut Painter) {
+        for cell in &self.cells {
+            painter.paint(cell.x as usize, cell.y as usize, Color::DarkGray);
+        }
+    }
+}
+
+/// builds procedurally generated wall layouts for escalating difficulty levels
+pub struct LevelGenerator;
+
+impl LevelGenerator {
+    /// generates the wall bars for `level` inside `field`, keeping the snake's body,
+    /// a safety runway ahead of its head, a minimum amount of open floor, and at
+    /// least one apple-eligible cell reachable from the head clear
+    fn generate(field: Rect, level: u64, snake: &Snake) -> Wall {
+        let width = (field.width as f64 - 3.0).max(0.0);
+        let height = (field.height as f64 - 3.0).max(0.0);
+        if width <= 0.0 || height <= 0.0 {
+            return Wall::default();
+        }
+
+        let forbidden = Self::forbidden_cells(snake);
+        let total_cells = ((width / 2.0 + 1.0) * (height + 1.0)) as usize;
+        let head = *snake.body.front().expect("snake always has a head");
+
+        let bar_count = level.saturating_sub(1).min(MAX_WALL_BARS);
+        let mut cells: Vec<Point> = Vec::new();
+        for _ in 0..bar_count {
+            let bar: Vec<Point> = Self::generate_bar(width, height)
+                .into_iter()
+                .filter(|cell| !forbidden.contains(cell) && !cells.contains(cell))
+                .collect();
+
+            let mut candidate_cells = cells.clone();
+            candidate_cells.extend(bar);
+
+            let open_cells = total_cells.saturating_sub(candidate_cells.len() + snake.body.len());
+            if open_cells < MIN_OPEN_CELLS {
+                break;
+            }
+
+            let reachable = Self::flood_fill(width, height, &candidate_cells, head);
+            let has_reachable_apple_cell = reachable.iter().any(|cell| !snake.body.contains(cell));
+            if !has_reachable_apple_cell {
+                break;
+            }
+
+            cells = candidate_cells;
+        }
+
+        Wall { cells }
+    }
+
+    /// flood-fills from `start` through cells that aren't walls, following the same
+    /// four moves the snake can make, to find everywhere the head can actually reach
+    fn flood_fill(width: f64, height: f64, walls: &[Point], start: Point) -> Vec<Point> {
+        let mut visited = vec![start];
+        let mut queue = VecDeque::from([start]);
+
+        while let Some(current) = queue.pop_front() {
+            let neighbors = [
+                Point {
+                    x: current.x,
+                    y: current.y - 1.0,
+                },
+                Point {
+                    x: current.x,
+                    y: current.y + 1.0,
+                },
+                Point {
+                    x: current.x - 2.0,
+                    y: current.y,
+                },
+                Point {
+                    x: current.x + 2.0,
+                    y: current.y,
+                },
+            ];
+
+            for neighbor in neighbors {
+                let in_bounds =
+                    neighbor.x >= 0.0 && neighbor.x <= width && neighbor.y >= 0.0 && neighbor.y <= height;
+                if in_bounds && !walls.contains(&neighbor) && !visited.contains(&neighbor) {
+                    visited.push(neighbor);
+                    queue.push_back(neighbor);
+                }
+            }
+        }
+
+        visited
+    }
+
+    /// cells new walls must never occupy: the snake's own body, plus a short runway
+    /// ahead of its head so it always has room to react to a fresh layout
+    fn forbidden_cells(snake: &Snake) -> Vec<Point> {
+        let mut forbidden: Vec<Point> = snake.body.iter().copied().collect();
+
+        if let Some(&head) = snake.body.front() {
+            let mut runway = head;
+            for _ in 0..HEAD_SAFETY_RUNWAY {
+                runway = match snake.direction {
+                    Direction::Up => Point {
+                        x: runway.x,
+                        y: runway.y - 1.0,
+                    },
+                    Direction::Down => Point {
+                        x: runway.x,
+                        y: runway.y + 1.0,
+                    },
+                    Direction::Left => Point {
+                        x: runway.x - 2.0,
+                        y: runway.y,
+                    },
+                    Direction::Right => Point {
+                        x: runway.x + 2.0,
+                        y: runway.y,
+                    },
+                };
+                forbidden.push(runway);
+            }
+        }
+
+        forbidden
+    }
+
+    /// generates a single random axis-aligned wall bar
+    fn generate_bar(width: f64, height: f64) -> Vec<Point> {
+        let mut bar = Vec::new();
+        if rand::random::<bool>() {
+            let y = (rand::random::<f64>() * height).floor();
+            let length = ((rand::random::<f64>() * width / 2.0) + 4.0).floor();
+            let start_x = (rand::random::<f64>() * (width - length).max(0.0) / 2.0).floor() * 2.0;
+            let mut x = start_x;
+            while x < start_x + length && x < width {
+                bar.push(Point { x, y });
+                x += 2.0;
+            }
+        } else {
+            let x = (rand::random::<f64>() * width / 2.0).floor() * 2.0;
+            let length = ((rand::random::<f64>() * height / 2.0) + 4.0).floor();
+            let start_y = (rand::random::<f64>() * (height - length).max(0.0)).floor();
+            let mut y = start_y;
+            while y < start_y + length && y < height {
+                bar.push(Point { x, y });
+                y += 1.0;
+            }
+        }
+        bar
     }
 }
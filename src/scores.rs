@@ -0,0 +1,112 @@
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::PathBuf;
+
+/// number of entries kept in the persisted high-score table
+const MAX_ENTRIES: usize = 10;
+
+/// name of the high-score file within the app's data directory
+const SCORES_FILE_NAME: &str = "high_scores.json";
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScoreEntry {
+    pub name: String,
+    pub score: u64,
+}
+
+/// a sorted, capped high-score table persisted to the user's data directory
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ScoreTable {
+    entries: Vec<ScoreEntry>,
+}
+
+impl ScoreTable {
+    /// loads the high-score table from disk, starting empty if none exists yet
+    pub fn load() -> Self {
+        fs::read_to_string(Self::file_path())
+            .ok()
+            .and_then(|contents| serde_json::from_str(&contents).ok())
+            .unwrap_or_default()
+    }
+
+    /// persists the high-score table to disk, creating the data directory if needed
+    pub fn save(&self) {
+        let path = Self::file_path();
+        if let Some(parent) = path.parent() {
+            let _ = fs::create_dir_all(parent);
+        }
+        if let Ok(contents) = serde_json::to_string_pretty(self) {
+            let _ = fs::write(path, contents);
+        }
+    }
+
+    /// true if `score` earns a spot in the table, either because it's not full
+    /// yet or because it beats the current lowest entry
+    pub fn qualifies(&self, score: u64) -> bool {
+        self.entries.len() < MAX_ENTRIES || self.entries.iter().any(|entry| score > entry.score)
+    }
+
+    /// inserts a new entry, keeping the table sorted highest-first and capped
+    /// at `MAX_ENTRIES`
+    pub fn insert(&mut self, name: String, score: u64) {
+        self.entries.push(ScoreEntry { name, score });
+        self.entries.sort_by_key(|entry| std::cmp::Reverse(entry.score));
+        self.entries.truncate(MAX_ENTRIES);
+    }
+
+    pub fn entries(&self) -> &[ScoreEntry] {
+        &self.entries
+    }
+
+    fn file_path() -> PathBuf {
+        dirs::data_dir()
+            .unwrap_or_else(std::env::temp_dir)
+            .join("snake_game")
+            .join(SCORES_FILE_NAME)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn filled_table() -> ScoreTable {
+        let mut table = ScoreTable::default();
+        for score in 1..=MAX_ENTRIES as u64 {
+            table.insert(format!("player{score}"), score);
+        }
+        table
+    }
+
+    #[test]
+    fn qualifies_when_table_not_full() {
+        let table = ScoreTable::default();
+
+        assert!(table.qualifies(0));
+    }
+
+    #[test]
+    fn qualifies_when_beating_the_lowest_entry() {
+        let table = filled_table();
+
+        assert!(table.qualifies(2));
+    }
+
+    #[test]
+    fn does_not_qualify_when_tying_the_lowest_entry() {
+        let table = filled_table();
+
+        assert!(!table.qualifies(1));
+    }
+
+    #[test]
+    fn insert_keeps_entries_sorted_highest_first_and_capped() {
+        let mut table = filled_table();
+
+        table.insert("newcomer".into(), 2);
+
+        let scores: Vec<u64> = table.entries().iter().map(|entry| entry.score).collect();
+        assert_eq!(scores.len(), MAX_ENTRIES);
+        assert_eq!(scores, vec![10, 9, 8, 7, 6, 5, 4, 3, 2, 2]);
+    }
+}